@@ -0,0 +1,131 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::cmp::*;
+use std::ops::Deref;
+
+use ::clear_on_drop::{ClearOnDrop, OwnedBox};
+
+
+/// A secret that is either owned, and cleared on drop, or merely
+/// borrowed, and left for its owner to clear.
+///
+/// Ported from the idea behind the `maybe-owned` crate.  Unlike `Cow`,
+/// `MaybeClear` does not require `T: ToOwned` or even `T: Clone`: the
+/// owned variant is built on `OwnedBox<T>` rather than `Owned<T>`, so it
+/// works with secret types that deliberately forbid cloning.  APIs can
+/// accept `impl Into<MaybeClear<T>>` and let callers pass either a
+/// freshly derived secret, which gets zeroed when it goes out of scope,
+/// or a long-lived borrowed one, which is left untouched because the
+/// caller still owns it.
+pub enum MaybeClear<'a, T: 'a + ?Sized> {
+    /// A secret we own; its backing memory is wiped on drop.
+    Owned(ClearOnDrop<T, OwnedBox<T>>),
+    /// A secret we merely borrow; the caller still owns it.
+    Borrowed(&'a T),
+}
+
+impl<'a, T: 'a + ?Sized> Deref for MaybeClear<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        match *self {
+            MaybeClear::Owned(ref o) => o,
+            MaybeClear::Borrowed(r) => r,
+        }
+    }
+}
+
+impl<'a, T: 'a> From<T> for MaybeClear<'a, T> {
+    #[inline]
+    fn from(t: T) -> MaybeClear<'a, T> {
+        MaybeClear::Owned(ClearOnDrop::new(OwnedBox::new(Box::new(t))))
+    }
+}
+
+impl<'a, T: 'a + ?Sized> From<&'a T> for MaybeClear<'a, T> {
+    #[inline]
+    fn from(r: &'a T) -> MaybeClear<'a, T> {
+        MaybeClear::Borrowed(r)
+    }
+}
+
+
+// --- Delegate derivable traits --- //
+
+impl<'a, T: 'a + ?Sized> fmt::Debug for MaybeClear<'a, T>
+    where T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+      {  fmt::Debug::fmt(&**self, f)  }
+}
+
+impl<'a, T: 'a + ?Sized> Hash for MaybeClear<'a, T>
+    where T: Hash
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {  (**self).hash(state);  }
+}
+
+impl<'a, T: 'a + ?Sized> PartialEq for MaybeClear<'a, T>
+    where T: PartialEq
+{
+    fn eq(&self, other: &Self) -> bool {  (**self).eq(&**other)  }
+}
+
+impl<'a, T: 'a + ?Sized> Eq for MaybeClear<'a, T> where T: Eq { }
+
+impl<'a, T: 'a + ?Sized> PartialOrd for MaybeClear<'a, T>
+    where T: PartialOrd
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {  (**self).partial_cmp(&**other)  }
+}
+
+impl<'a, T: 'a + ?Sized> Ord for MaybeClear<'a, T>
+    where T: Ord
+{
+    fn cmp(&self, other: &Self) -> Ordering {  (**self).cmp(&**other)  }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A type that forbids cloning, to check `MaybeClear` really does not
+    // require `T: Clone`.
+    #[derive(Debug, PartialEq)]
+    struct NoClone([u8; 4]);
+
+    #[test]
+    fn owned() {
+        let secret: MaybeClear<NoClone> = NoClone([1, 2, 3, 4]).into();
+        assert_eq!(*secret, NoClone([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn borrowed() {
+        let data = NoClone([5, 6, 7, 8]);
+        let secret: MaybeClear<NoClone> = (&data).into();
+        assert_eq!(*secret, data);
+    }
+
+    #[test]
+    fn owned_clears_on_drop() {
+        let place: *const u8;
+        {
+            let secret: MaybeClear<NoClone> = NoClone([1, 2, 3, 4]).into();
+            place = &(*secret).0[0];
+        }
+        unsafe { assert_eq!(*place, 0); }
+    }
+
+    #[test]
+    fn borrowed_is_left_untouched_after_drop() {
+        let data = NoClone([5, 6, 7, 8]);
+        {
+            let secret: MaybeClear<NoClone> = (&data).into();
+            assert_eq!(*secret, data);
+        }
+        assert_eq!(data, NoClone([5, 6, 7, 8]));
+    }
+}
@@ -58,6 +58,13 @@ pub fn owned_clear_on_drop<T>(t: T) -> ClearOwnedOnDrop<T>
 ///    unsafe { assert_eq!(*place.offset(i), 0); }
 /// }
 /// ```
+/// `Owned<T>` deliberately does not implement `StableAddress`: it stores
+/// `T` inline, so moving an `Owned<T>` (or a `ClearOnDrop` wrapping one)
+/// moves `T` right along with it, unlike `Box<T>` or `OwnedBox<T>`,
+/// whose inner `T` stays put on the heap no matter where the handle
+/// goes.  `ClearOnDrop::map`/`map_mut` require `StableAddress` for
+/// exactly this reason, so projecting a view requires an owner like
+/// `OwnedBox<T>` instead.
 pub struct Owned<T>(T) where T: Copy + ?Sized;
 
 impl<T> Owned<T> where T: Copy + ?Sized {
@@ -65,6 +72,22 @@ impl<T> Owned<T> where T: Copy + ?Sized {
     pub fn new(t: T) -> Owned<T> {  Owned(t)  }
 }
 
+impl<T> Owned<T> where T: Copy {
+    /// Map the wrapped value through `f`, producing a differently-typed
+    /// `Owned<U>`.
+    ///
+    /// This is a plain value transform, not a clearing operation: bare
+    /// `Owned<T>` has no `Drop` impl of its own (clearing only happens
+    /// via `ClearOnDrop<T, Owned<T>>`'s `Drop`), so calling `map` moves
+    /// `T` through `f` exactly as calling `f` directly would, with no
+    /// extra guarantee about what happens to the old bytes.
+    pub fn map<U, F>(self, f: F) -> Owned<U>
+        where U: Copy, F: FnOnce(T) -> U
+    {
+        Owned(f(self.0))
+    }
+}
+
 
 // --- Implement pointer traits --- //
 
@@ -205,10 +228,148 @@ mod tests {
            place = &key[0];
            // This causes the test to fail!
            // ::std::mem::drop(key);
-        } 
+        }
         for i in 0..7 {
             unsafe { assert_eq!(*place.offset(i), 0); }
         }
     }
 }
 
+
+/// Marker for owner types whose `Deref` target keeps the same address
+/// even when the owner itself is moved, ported from `owning_ref`'s
+/// `StableAddress`.  `Box<T>` is the canonical example: moving the `Box`
+/// only moves the pointer, not the heap allocation it points at.
+///
+/// # Safety
+///
+/// Implementors must guarantee that `Deref::deref` returns the same
+/// address across moves of `self`, so pointers taken through it remain
+/// valid for as long as the owner is alive.
+pub unsafe trait StableAddress: Deref { }
+
+unsafe impl<T: ?Sized> StableAddress for Box<T> { }
+
+
+/// Abreviation for `ClearOnDrop` composed with `OwnedBox`
+pub type ClearOwnedBoxOnDrop<T> = ::clear_on_drop::ClearOnDrop<T, OwnedBox<T>>;
+    // where T: ?Sized;
+
+/// Abreviation for `ClearOnDrop::new(OwnedBox::new(_))`
+#[inline(always)]
+pub fn owned_box_clear_on_drop<T>(t: Box<T>) -> ClearOwnedBoxOnDrop<T>
+    where T: ?Sized
+{
+    ::clear_on_drop::ClearOnDrop::new(OwnedBox::new(t))
+}
+
+
+/// Wraps a heap-allocated value so it masquerades as a reference, the
+/// way `Owned<T>` does for inline `Copy` values.
+///
+/// `Owned<T>` requires `T: Copy` because it stores `T` directly; that
+/// excludes heap-owned secrets like `Box<[u8]>` or `Vec<u8>` whose
+/// backing allocation is exactly what needs zeroing before it is freed.
+/// `OwnedBox<T>` covers that case: it owns a `Box<T>`, so `T` need not be
+/// `Copy`, and `Deref`/`DerefMut` reach straight through to the heap
+/// contents that `ClearOnDrop`'s clearing pass operates on, before the
+/// `Box` itself is dropped and the allocation freed.
+///
+/// Example
+///
+/// ```
+/// # use clear_on_drop::ClearOnDrop;
+/// # use clear_on_drop::OwnedBox;
+/// let place: *const u8;
+/// {
+///     let mut key = ClearOnDrop::new(OwnedBox::new(vec![1u8, 2, 3, 4].into_boxed_slice()));
+///     key[0] = 9;
+///     place = &key[0];
+/// }
+/// unsafe { assert_eq!(*place, 0); }
+/// ```
+pub struct OwnedBox<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> OwnedBox<T> {
+    /// Wrap a heap-allocated value so it masquerades as a reference.
+    pub fn new(t: Box<T>) -> OwnedBox<T> {  OwnedBox(t)  }
+}
+
+
+// --- Implement pointer traits --- //
+
+impl<T: ?Sized> Deref for OwnedBox<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for OwnedBox<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for OwnedBox<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> AsMut<T> for OwnedBox<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for OwnedBox<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for OwnedBox<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+unsafe impl<T: ?Sized> StableAddress for OwnedBox<T> { }
+
+
+// --- Delegate derivable traits --- //
+
+impl<T: ?Sized> fmt::Debug for OwnedBox<T>
+    where T: fmt::Debug
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+      {  fmt::Debug::fmt(&self.0, f)  }
+}
+
+
+#[cfg(test)]
+mod owned_box_tests {
+    use super::*;
+    use clear_on_drop::ClearOnDrop;
+
+    #[test]
+    fn owned_box() {
+        let place: *const u8;
+        {
+            let mut key = ClearOnDrop::new(OwnedBox::new(vec![1u8, 2, 3, 4].into_boxed_slice()));
+            key[0] = 9;
+            place = &key[0];
+        }
+        unsafe { assert_eq!(*place, 0); }
+    }
+}
+
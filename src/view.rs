@@ -0,0 +1,174 @@
+use std::ops::{Deref, DerefMut};
+
+use ::clear_on_drop::{ClearOnDrop, StableAddress};
+
+
+/// An owning-ref-style view onto a sub-field of a value guarded by
+/// `ClearOnDrop`.
+///
+/// `ClearView` is produced by [`ClearOnDrop::map`](::clear_on_drop::ClearOnDrop::map)
+/// and mirrors `OwningRef::map` from the `owning_ref` crate: it keeps the
+/// original clearing owner alive, so the owner's full `Drop` impl still
+/// wipes the whole backing allocation, while `Deref` only ever exposes
+/// the narrowed sub-field the caller asked for.
+///
+/// Example
+///
+/// ```
+/// # use clear_on_drop::ClearOnDrop;
+/// # use clear_on_drop::OwnedBox;
+/// let view = ClearOnDrop::new(OwnedBox::new(vec![1u8, 2, 3, 4].into_boxed_slice()))
+///     .map(|a| &a[1..3]);
+/// assert_eq!(&*view, &[2, 3]);
+/// ```
+pub struct ClearView<O, U: ?Sized> {
+    owner: O,
+    ptr: *const U,
+}
+
+impl<O, U: ?Sized> ClearView<O, U> {
+    /// `ptr` must have been taken through `owner`'s `StableAddress` deref
+    /// target, so it stays valid across every later move of `owner` (and
+    /// of this view) for as long as `owner` lives here.
+    fn new(owner: O, ptr: *const U) -> ClearView<O, U> {
+        ClearView { owner, ptr }
+    }
+}
+
+impl<O, U: ?Sized> Deref for ClearView<O, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        unsafe { &*self.ptr }
+    }
+}
+
+impl<T: ?Sized, Place> ClearOnDrop<T, Place>
+    where Place: DerefMut<Target = T> + StableAddress
+{
+    /// Project this clearing owner onto a sub-field `&U` of `T`, keeping
+    /// the whole owner alive (and clearable) behind the returned
+    /// `ClearView`.
+    ///
+    /// Modeled on `OwningRef::map`: `f` runs once, against the owner's
+    /// stable deref target, to compute the pointer the view will hand
+    /// out; the owner is then moved into the view so that pointer stays
+    /// valid for as long as the view exists.  `Place: StableAddress` is
+    /// required so that move, which relocates `self`'s own bytes, cannot
+    /// relocate the `T` the pointer was taken from; `Owned<T>` does not
+    /// implement `StableAddress` for exactly this reason, so owners like
+    /// `OwnedBox<T>` are needed here instead.
+    pub fn map<U: ?Sized, F>(self, f: F) -> ClearView<Self, U>
+        where F: FnOnce(&T) -> &U
+    {
+        let ptr = f(&*self) as *const U;
+        ClearView::new(self, ptr)
+    }
+}
+
+/// Mutable counterpart of `ClearView`, produced by `ClearOnDrop::map_mut`.
+///
+/// Mirrors `OwningRefMut::map_mut`: the wrapper holds the clearing owner
+/// plus a `*mut U` into one of its sub-fields.  Because `map_mut`
+/// consumes `self`, only one such projection can exist at a time, so
+/// there is never an aliasing `&mut` into the owner's allocation.
+pub struct ClearViewMut<O, U: ?Sized> {
+    owner: O,
+    ptr: *mut U,
+}
+
+impl<O, U: ?Sized> ClearViewMut<O, U> {
+    /// `ptr` must have been taken through `owner`'s `StableAddress` deref
+    /// target, so it stays valid across every later move of `owner` (and
+    /// of this view) for as long as `owner` lives here.
+    fn new(owner: O, ptr: *mut U) -> ClearViewMut<O, U> {
+        ClearViewMut { owner, ptr }
+    }
+}
+
+impl<O, U: ?Sized> Deref for ClearViewMut<O, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        unsafe { &*self.ptr }
+    }
+}
+
+impl<O, U: ?Sized> DerefMut for ClearViewMut<O, U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut U {
+        unsafe { &mut *self.ptr }
+    }
+}
+
+impl<T: ?Sized, Place> ClearOnDrop<T, Place>
+    where Place: DerefMut<Target = T> + StableAddress
+{
+    /// Project this clearing owner onto a mutable sub-field `&mut U` of
+    /// `T`, keeping the whole owner alive (and clearable) behind the
+    /// returned `ClearViewMut`.
+    ///
+    /// As with `map`, `f` runs once against the owner's stable deref
+    /// target before the owner is moved into the view; consuming `self`
+    /// guarantees no other `&mut` into the same allocation can coexist
+    /// with the one handed out here.  Useful for filling a key schedule
+    /// in place, e.g. writing round keys into slices of a larger buffer,
+    /// without ever copying secret bytes to an uncleared temporary.
+    ///
+    /// `Place: StableAddress` is required for the same reason as in
+    /// `map`: moving `self` into the returned view must not relocate the
+    /// `T` that `ptr` was taken from.
+    pub fn map_mut<U: ?Sized, F>(mut self, f: F) -> ClearViewMut<Self, U>
+        where F: FnOnce(&mut T) -> &mut U
+    {
+        let ptr = f(&mut *self) as *mut U;
+        ClearViewMut::new(self, ptr)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clear_on_drop::{ClearOnDrop, OwnedBox};
+
+    #[test]
+    fn map() {
+        let view = ClearOnDrop::new(OwnedBox::new(vec![1u8, 2, 3, 4].into_boxed_slice()))
+            .map(|a| &a[1..3]);
+        assert_eq!(&*view, &[2, 3]);
+    }
+
+    #[test]
+    fn map_clears_whole_owner_on_drop() {
+        let place: *const u8;
+        {
+            let view = ClearOnDrop::new(OwnedBox::new(vec![1u8, 2, 3, 4].into_boxed_slice()))
+                .map(|a| &a[1..3]);
+            place = &view[0];
+        }
+        unsafe { assert_eq!(*place, 0); }
+    }
+
+    #[test]
+    fn map_mut() {
+        let mut view = ClearOnDrop::new(OwnedBox::new(vec![1u8, 2, 3, 4].into_boxed_slice()))
+            .map_mut(|a| &mut a[1..3]);
+        view[0] = 9;
+        assert_eq!(&*view, &[9, 3]);
+    }
+
+    #[test]
+    fn map_mut_clears_whole_owner_on_drop() {
+        let place: *const u8;
+        {
+            let mut view = ClearOnDrop::new(OwnedBox::new(vec![1u8, 2, 3, 4].into_boxed_slice()))
+                .map_mut(|a| &mut a[1..3]);
+            view[0] = 9;
+            place = &view[0];
+        }
+        unsafe { assert_eq!(*place, 0); }
+    }
+}